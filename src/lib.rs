@@ -15,12 +15,14 @@
 //!
 //! ```
 //! # use embedded_hal::i2c::I2c;
+//! # use embedded_hal_mock::eh1::delay::NoopDelay;
 //! # use embedded_hal_mock::eh1::i2c::{Mock as I2cMock, Transaction as I2cTransaction};
 //! use ds3502::{Ds3502, Wiper, ControlRegisterMode};
 //! # use ds3502::Ds3502Error;
 //! # fn main() -> Result<(), Ds3502Error> {
 //! # let expectations = [
 //! # I2cTransaction::write(0x28, vec![2, 0x80]),
+//! # I2cTransaction::write_read(0x28, vec![0x00], vec![0]),
 //! # I2cTransaction::write(0x28, vec![0, 88]),
 //! # I2cTransaction::write(0x28, vec![0x2, ControlRegisterMode::WiperAndInitialValue as u8]),
 //! # I2cTransaction::write(0x28, vec![0x0, 123]),
@@ -38,7 +40,8 @@
 //!
 //! // Set wiper value and save to EEPROM
 //! let wv = Wiper::try_from(123)?;
-//! digipot.write_and_save_wiper(wv);
+//! let mut delay = NoopDelay;
+//! digipot.write_and_save_wiper(wv, &mut delay);
 //! # i2c_mock.done();
 //! # Ok(())
 //! # }
@@ -70,9 +73,17 @@
 //! work by you, as defined in the Apache-2.0 license, shall be dual licensed as above, without any
 //! additional terms or conditions.
 
-use embedded_hal::i2c::{Error as I2cError, I2c};
+use embedded_hal::delay::DelayNs;
+use embedded_hal::i2c::{Error as I2cError, ErrorKind, I2c};
+use embedded_hal_async::delay::DelayNs as AsyncDelayNs;
 use embedded_hal_async::i2c::I2c as AsyncI2c;
 
+/// The DS3502's datasheet-specified EEPROM write cycle time, in microseconds.
+///
+/// [`Ds3502::write_and_save_wiper`] waits this long after writing the wiper register before
+/// switching the control register back, so the commit settles instead of racing the I2C clock.
+const EEPROM_WRITE_TIME_US: u32 = 20_000;
+
 /// Represents the  I2C address for the DS3502.
 ///
 /// The DS3502 has address pins A1 and A0 to natively support up to four
@@ -108,6 +119,12 @@ pub struct Config {
 
     /// By default, EEPROM saves are disabled, see [`ControlRegisterMode`] for more details.
     pub mode: ControlRegisterMode,
+
+    /// Maximum number of [`Ds3502::write_and_save_wiper`] calls to allow, to avoid exceeding
+    /// the EEPROM's rated endurance in long-running applications.
+    ///
+    /// `None` (the default) allows an unlimited number of saves; see [`Ds3502::saves_remaining`].
+    pub save_budget: Option<u32>,
 }
 
 impl Default for Config {
@@ -115,6 +132,7 @@ impl Default for Config {
         Self {
             i2c_addr: I2cAddr::Default,
             mode: ControlRegisterMode::WiperOnly,
+            save_budget: None,
         }
     }
 }
@@ -146,6 +164,14 @@ pub enum Ds3502Error {
     /// Error on the I2C Bus.
     #[error("I2C error: {0}")]
     I2cError(error::I2cErrorKind),
+
+    /// No device acknowledged at the probed I2C address.
+    #[error("No DS3502 found at the probed address.")]
+    DeviceNotFound,
+
+    /// The configured `save_budget` of EEPROM writes has been used up.
+    #[error("EEPROM save budget exhausted.")]
+    SaveBudgetExhausted,
 }
 
 impl<E> From<E> for Ds3502Error
@@ -219,6 +245,7 @@ pub enum ControlRegisterMode {
 /// # fn main() -> Result<(), Ds3502Error> {
 /// # let expectations = [
 /// # I2cTransaction::write(0x28, vec![2, 0x80]),
+/// # I2cTransaction::write_read(0x28, vec![0x00], vec![0]),
 /// # I2cTransaction::write(0x28, vec![0, 88]),
 /// # ];
 /// # let mut i2c_mock = I2cMock::new(&expectations);
@@ -234,17 +261,52 @@ pub enum ControlRegisterMode {
 pub struct Ds3502<I2C> {
     i2c: I2C,
     config: Config,
+    last_wiper: Wiper,
+    saves_used: u32,
 }
 
 impl<I2C> Ds3502<I2C> {
     fn new(i2c: I2C, config: Config) -> Self {
-        Ds3502 { i2c, config }
+        Ds3502 {
+            i2c,
+            config,
+            last_wiper: Wiper(0),
+            saves_used: 0,
+        }
     }
 
-    /// Get the driver's current control register mode.  
+    /// Get the driver's current control register mode.
     pub fn mode(&self) -> ControlRegisterMode {
         self.config.mode
     }
+
+    /// Get the last wiper value written or read back by this driver.
+    ///
+    /// This is tracked locally, seeded from the device by `*_init`, and updated on every
+    /// write or read; it does not re-query the device on its own, so it can go stale if the
+    /// wiper is changed by something other than this driver.
+    #[must_use]
+    pub fn last_wiper(&self) -> Wiper {
+        self.last_wiper
+    }
+
+    /// Estimate how many more [`Ds3502::write_and_save_wiper`] calls are allowed under
+    /// `config.save_budget`.
+    ///
+    /// Returns `None` if no budget was configured, i.e. saves are unlimited.
+    #[must_use]
+    pub fn saves_remaining(&self) -> Option<u32> {
+        self.config
+            .save_budget
+            .map(|budget| budget.saturating_sub(self.saves_used))
+    }
+
+    fn check_save_budget(&self) -> Result<(), Ds3502Error> {
+        match self.config.save_budget {
+            Some(budget) if self.saves_used >= budget => Err(Ds3502Error::SaveBudgetExhausted),
+            _ => Ok(()),
+        }
+    }
 }
 
 impl<I2C: I2c> Ds3502<I2C> {
@@ -252,14 +314,45 @@ impl<I2C: I2c> Ds3502<I2C> {
     ///
     /// The default [`Config`] disables writes to initial value register. See [Differences from default behavior](`Config#differences-from-default-behavior`) for more details.
     ///
+    /// Also reads back the wiper register so the driver's cached [`Self::last_wiper`] reflects
+    /// the value the device loaded from EEPROM at power-up, rather than assuming 0. This keeps
+    /// the very first [`Self::ramp_to`] call glitch-free instead of sweeping from a fictitious
+    /// starting position.
+    ///
     /// # Errors
     /// Will return `Err` on I2C bus related problems.
     pub fn blocking_init(i2c: I2C, config: Config) -> Result<Self, Ds3502Error> {
         let mut pot = Ds3502::new(i2c, config);
         pot.set_mode(config.mode)?;
+        pot.read_wiper()?;
         Ok(pot)
     }
 
+    /// Probe the bus at `config.i2c_addr` and only construct a driver if a device acknowledges.
+    ///
+    /// # Errors
+    /// Returns [`Ds3502Error::DeviceNotFound`] if nothing acknowledges the address, or `Err`
+    /// on a genuine I2C bus problem.
+    pub fn detect(mut i2c: I2C, config: Config) -> Result<Self, Ds3502Error> {
+        if !Self::probe(&mut i2c, config.i2c_addr)? {
+            return Err(Ds3502Error::DeviceNotFound);
+        }
+        Self::blocking_init(i2c, config)
+    }
+
+    /// Attempt a single-byte read at `addr`, returning whether a device acknowledged it.
+    ///
+    /// A [`ErrorKind::NoAcknowledge`] is treated as "no device present" rather than an error,
+    /// while any other error (e.g. arbitration loss) still propagates.
+    fn probe(i2c: &mut I2C, addr: I2cAddr) -> Result<bool, Ds3502Error> {
+        let mut buf = [0u8; 1];
+        match i2c.read(addr as u8, &mut buf) {
+            Ok(()) => Ok(true),
+            Err(e) if matches!(e.kind(), ErrorKind::NoAcknowledge(_)) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     /// Changes control register mode to allow for saving the wiper value to the EEPROM.
     ///
     /// By default, the wiper value from `write_wiper` is not saved following reset.
@@ -277,11 +370,23 @@ impl<I2C: I2c> Ds3502<I2C> {
 
     /// Sets the wiper value and saves it to the EEPROM.
     ///
+    /// Waits the datasheet's EEPROM write cycle time (around 20ms) after the write before
+    /// switching the control register back, so the EEPROM commit settles instead of racing
+    /// the I2C clock.
+    ///
     /// # Errors
-    /// Will return `Err` on I2C bus related problems.
-    pub fn write_and_save_wiper(&mut self, value: Wiper) -> Result<(), Ds3502Error> {
+    /// Will return `Err` on I2C bus related problems, or
+    /// [`Ds3502Error::SaveBudgetExhausted`] if `config.save_budget` has been used up.
+    pub fn write_and_save_wiper(
+        &mut self,
+        value: Wiper,
+        delay: &mut impl DelayNs,
+    ) -> Result<(), Ds3502Error> {
+        self.check_save_budget()?;
         self.set_mode(ControlRegisterMode::WiperAndInitialValue)?;
         self.write_wiper(value)?;
+        self.saves_used += 1;
+        delay.delay_us(EEPROM_WRITE_TIME_US);
         self.set_mode(ControlRegisterMode::WiperOnly)?;
         Ok(())
     }
@@ -293,6 +398,66 @@ impl<I2C: I2c> Ds3502<I2C> {
     pub fn write_wiper(&mut self, value: Wiper) -> Result<(), Ds3502Error> {
         self.i2c
             .write(self.config.i2c_addr as u8, &[0x00, value.0])?;
+        self.last_wiper = value;
+        Ok(())
+    }
+
+    /// Read the current wiper position (WR register) back from the device.
+    ///
+    /// Useful for confirming the pot's state after a reset, rather than assuming
+    /// the last value written with [`Self::write_wiper`] still holds.
+    ///
+    /// # Errors
+    /// Will return `Err` on I2C bus related problems.
+    pub fn read_wiper(&mut self) -> Result<Wiper, Ds3502Error> {
+        let mut buf = [0u8; 1];
+        self.i2c
+            .write_read(self.config.i2c_addr as u8, &[0x00], &mut buf)?;
+        let wiper = Wiper::try_from(buf[0])?;
+        self.last_wiper = wiper;
+        Ok(wiper)
+    }
+
+    /// Read the initial value register (IVR), the value saved to the EEPROM.
+    ///
+    /// # Errors
+    /// Will return `Err` on I2C bus related problems.
+    pub fn read_ivr(&mut self) -> Result<Wiper, Ds3502Error> {
+        let mut buf = [0u8; 1];
+        self.i2c
+            .write_read(self.config.i2c_addr as u8, &[0x01], &mut buf)?;
+        Wiper::try_from(buf[0])
+    }
+
+    /// Sweep the wiper from its last known position ([`Self::last_wiper`]) to `target`, one
+    /// step at a time.
+    ///
+    /// Writing the wiper directly to a far-away value can cause an abrupt resistance jump,
+    /// which shows up as an audible pop or voltage transient in analog signal paths. This
+    /// steps through every intermediate [`Wiper`] value instead, pausing `step_us`
+    /// microseconds between writes, and works in both the increasing and decreasing direction.
+    ///
+    /// # Errors
+    /// Will return `Err` on I2C bus related problems.
+    pub fn ramp_to(
+        &mut self,
+        target: Wiper,
+        delay: &mut impl DelayNs,
+        step_us: u32,
+    ) -> Result<(), Ds3502Error> {
+        let start = self.last_wiper.inner();
+        let end = target.inner();
+        if start <= end {
+            for step in (start + 1)..=end {
+                self.write_wiper(Wiper::try_from(step)?)?;
+                delay.delay_us(step_us);
+            }
+        } else {
+            for step in (end..start).rev() {
+                self.write_wiper(Wiper::try_from(step)?)?;
+                delay.delay_us(step_us);
+            }
+        }
         Ok(())
     }
 }
@@ -302,11 +467,17 @@ impl<I2C: AsyncI2c> Ds3502<I2C> {
     ///
     /// The default [`Config`] disables writes to initial value register. See [Differences from default behavior](`Config#differences-from-default-behavior`) for more details.
     ///
+    /// Also reads back the wiper register so the driver's cached last wiper value reflects
+    /// the value the device loaded from EEPROM at power-up, rather than assuming 0. This keeps
+    /// the very first [`Self::async_ramp_to`] call glitch-free instead of sweeping from a
+    /// fictitious starting position.
+    ///
     /// # Errors
     /// Will return `Err` on I2C Bus problems.
     pub async fn async_init(i2c: I2C, config: Config) -> Result<Self, Ds3502Error> {
         let mut pot = Ds3502::new(i2c, config);
         pot.async_set_mode(config.mode).await?;
+        pot.async_read_wiper().await?;
         Ok(pot)
     }
 
@@ -318,17 +489,28 @@ impl<I2C: AsyncI2c> Ds3502<I2C> {
         self.i2c
             .write(self.config.i2c_addr as u8, &[0x00, value.0])
             .await?;
+        self.last_wiper = value;
         Ok(())
     }
 
-    /// Sets the wiper value and saves it to the EEPROM.
+    /// Sets the wiper value and saves it to the EEPROM (asynchronously).
+    ///
+    /// Read details from `write_and_save_wiper` to learn more.
     ///
     /// # Errors
-    /// Will return `Err` on I2C Bus problems.
-    pub async fn async_write_and_save_wiper(&mut self, value: Wiper) -> Result<(), Ds3502Error> {
+    /// Will return `Err` on I2C Bus problems, or [`Ds3502Error::SaveBudgetExhausted`] if
+    /// `config.save_budget` has been used up.
+    pub async fn async_write_and_save_wiper(
+        &mut self,
+        value: Wiper,
+        delay: &mut impl AsyncDelayNs,
+    ) -> Result<(), Ds3502Error> {
+        self.check_save_budget()?;
         self.async_set_mode(ControlRegisterMode::WiperAndInitialValue)
             .await?;
         self.async_write_wiper(value).await?;
+        self.saves_used += 1;
+        delay.delay_us(EEPROM_WRITE_TIME_US).await;
         self.async_set_mode(ControlRegisterMode::WiperOnly).await?;
         Ok(())
     }
@@ -345,10 +527,88 @@ impl<I2C: AsyncI2c> Ds3502<I2C> {
             .await?;
         Ok(())
     }
+
+    /// Read the current wiper position (WR register) back from the device (asynchronously).
+    ///
+    /// # Errors
+    /// Will return `Err` on I2C Bus problems.
+    pub async fn async_read_wiper(&mut self) -> Result<Wiper, Ds3502Error> {
+        let mut buf = [0u8; 1];
+        self.i2c
+            .write_read(self.config.i2c_addr as u8, &[0x00], &mut buf)
+            .await?;
+        let wiper = Wiper::try_from(buf[0])?;
+        self.last_wiper = wiper;
+        Ok(wiper)
+    }
+
+    /// Read the initial value register (IVR), the value saved to the EEPROM (asynchronously).
+    ///
+    /// # Errors
+    /// Will return `Err` on I2C Bus problems.
+    pub async fn async_read_ivr(&mut self) -> Result<Wiper, Ds3502Error> {
+        let mut buf = [0u8; 1];
+        self.i2c
+            .write_read(self.config.i2c_addr as u8, &[0x01], &mut buf)
+            .await?;
+        Wiper::try_from(buf[0])
+    }
+
+    /// Sweep the wiper from its last known position ([`Self::last_wiper`]) to `target`, one
+    /// step at a time (asynchronously).
+    ///
+    /// Read details from `ramp_to` to learn more.
+    ///
+    /// # Errors
+    /// Will return `Err` on I2C Bus problems.
+    pub async fn async_ramp_to(
+        &mut self,
+        target: Wiper,
+        delay: &mut impl AsyncDelayNs,
+        step_us: u32,
+    ) -> Result<(), Ds3502Error> {
+        let start = self.last_wiper.inner();
+        let end = target.inner();
+        if start <= end {
+            for step in (start + 1)..=end {
+                self.async_write_wiper(Wiper::try_from(step)?).await?;
+                delay.delay_us(step_us).await;
+            }
+        } else {
+            for step in (end..start).rev() {
+                self.async_write_wiper(Wiper::try_from(step)?).await?;
+                delay.delay_us(step_us).await;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Probe all four selectable I2C addresses (0x28-0x2b) and report which are populated.
+///
+/// A [`ErrorKind::NoAcknowledge`] at an address is treated as "no device present" rather
+/// than an error; any other bus error (e.g. arbitration loss) still propagates.
+///
+/// # Errors
+/// Will return `Err` on a genuine I2C bus problem.
+pub fn scan<I2C: I2c>(i2c: &mut I2C) -> Result<[bool; 4], Ds3502Error> {
+    let addrs = [
+        I2cAddr::Default,
+        I2cAddr::Address0,
+        I2cAddr::Address1,
+        I2cAddr::Address01,
+    ];
+    let mut found = [false; 4];
+    for (slot, addr) in found.iter_mut().zip(addrs) {
+        *slot = Ds3502::<I2C>::probe(i2c, addr)?;
+    }
+    Ok(found)
 }
 
 #[cfg(test)]
 mod test {
+    use embedded_hal::i2c::NoAcknowledgeSource;
+    use embedded_hal_mock::eh1::delay::NoopDelay;
     use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
     use futures_lite::future::block_on;
 
@@ -363,7 +623,7 @@ mod test {
         let wv = Wiper::try_from(88)?;
         digipot.write_wiper(wv)?;
         let wv = Wiper::try_from(23)?;
-        digipot.write_and_save_wiper(wv)?;
+        digipot.write_and_save_wiper(wv, &mut NoopDelay)?;
         Ok(())
     }
 
@@ -372,7 +632,9 @@ mod test {
         let wv = Wiper::try_from(88)?;
         digipot.async_write_wiper(wv).await?;
         let wv = Wiper::try_from(23)?;
-        digipot.async_write_and_save_wiper(wv).await?;
+        digipot
+            .async_write_and_save_wiper(wv, &mut NoopDelay)
+            .await?;
         Ok(())
     }
 
@@ -381,6 +643,7 @@ mod test {
         let expectations = [
             // {blocking,async}_init
             Transaction::write(0x28, vec![0x2, ControlRegisterMode::WiperOnly as u8]),
+            Transaction::write_read(0x28, vec![0x00], vec![0]),
             Transaction::write(0x28, vec![0x0, 88]),
             // {async_}write_and_save_wiper
             Transaction::write(
@@ -404,4 +667,214 @@ mod test {
         blocking_i2c.done();
         async_i2c.done();
     }
+
+    fn run_blocking_read_back(i2c: Mock) -> Result<(), Ds3502Error> {
+        let mut digipot = Ds3502::blocking_init(i2c, Default::default())?;
+        let wiper = digipot.read_wiper()?;
+        assert_eq!(wiper.inner(), 88);
+        let ivr = digipot.read_ivr()?;
+        assert_eq!(ivr.inner(), 23);
+        Ok(())
+    }
+
+    async fn run_async_read_back(i2c: Mock) -> Result<(), Ds3502Error> {
+        let mut digipot = Ds3502::async_init(i2c, Default::default()).await?;
+        let wiper = digipot.async_read_wiper().await?;
+        assert_eq!(wiper.inner(), 88);
+        let ivr = digipot.async_read_ivr().await?;
+        assert_eq!(ivr.inner(), 23);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_back_both() {
+        let expectations = [
+            // {blocking,async}_init
+            Transaction::write(0x28, vec![0x2, ControlRegisterMode::WiperOnly as u8]),
+            Transaction::write_read(0x28, vec![0x00], vec![88]),
+            // {async_}read_wiper
+            Transaction::write_read(0x28, vec![0x00], vec![88]),
+            // {async_}read_ivr
+            Transaction::write_read(0x28, vec![0x01], vec![23]),
+        ];
+        let mut blocking_i2c = Mock::new(&expectations);
+        let mut async_i2c = Mock::new(&expectations);
+
+        run_blocking_read_back(blocking_i2c.clone()).unwrap();
+        block_on(run_async_read_back(async_i2c.clone())).unwrap();
+
+        assert_eq!(
+            blocking_i2c.clone().collect::<vec::Vec<_>>(),
+            async_i2c.clone().collect::<vec::Vec<_>>(),
+        );
+
+        blocking_i2c.done();
+        async_i2c.done();
+    }
+
+    #[test]
+    fn test_detect_found() {
+        let expectations = [
+            Transaction::read(0x28, vec![0]),
+            Transaction::write(0x28, vec![0x2, ControlRegisterMode::WiperOnly as u8]),
+            Transaction::write_read(0x28, vec![0x00], vec![0]),
+        ];
+        let mut i2c = Mock::new(&expectations);
+
+        let digipot = Ds3502::detect(i2c.clone(), Default::default()).unwrap();
+        assert_eq!(digipot.mode(), ControlRegisterMode::WiperOnly);
+
+        i2c.done();
+    }
+
+    #[test]
+    fn test_detect_not_found() {
+        let expectations = [Transaction::read(0x28, vec![0])
+            .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address))];
+        let mut i2c = Mock::new(&expectations);
+
+        let err = Ds3502::detect(i2c.clone(), Default::default()).unwrap_err();
+        assert_eq!(err, Ds3502Error::DeviceNotFound);
+
+        i2c.done();
+    }
+
+    #[test]
+    fn test_scan() {
+        let expectations = [
+            Transaction::read(0x28, vec![0]),
+            Transaction::read(0x29, vec![0])
+                .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)),
+            Transaction::read(0x2a, vec![0])
+                .with_error(ErrorKind::NoAcknowledge(NoAcknowledgeSource::Address)),
+            Transaction::read(0x2b, vec![0]),
+        ];
+        let mut i2c = Mock::new(&expectations);
+
+        let found = scan(&mut i2c).unwrap();
+        assert_eq!(found, [true, false, false, true]);
+
+        i2c.done();
+    }
+
+    fn run_blocking_ramp(i2c: Mock) -> Result<(), Ds3502Error> {
+        let mut digipot = Ds3502::blocking_init(i2c, Default::default())?;
+        digipot.write_wiper(Wiper::try_from(2)?)?;
+        digipot.ramp_to(Wiper::try_from(5)?, &mut NoopDelay, 10)?;
+        Ok(())
+    }
+
+    async fn run_async_ramp(i2c: Mock) -> Result<(), Ds3502Error> {
+        let mut digipot = Ds3502::async_init(i2c, Default::default()).await?;
+        digipot.async_write_wiper(Wiper::try_from(2)?).await?;
+        digipot
+            .async_ramp_to(Wiper::try_from(5)?, &mut NoopDelay, 10)
+            .await?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_ramp_to_both() {
+        let expectations = [
+            // {blocking,async}_init
+            Transaction::write(0x28, vec![0x2, ControlRegisterMode::WiperOnly as u8]),
+            Transaction::write_read(0x28, vec![0x00], vec![0]),
+            // {async_}write_wiper(2)
+            Transaction::write(0x28, vec![0x0, 2]),
+            // {async_}ramp_to(5) steps through 3, 4, 5
+            Transaction::write(0x28, vec![0x0, 3]),
+            Transaction::write(0x28, vec![0x0, 4]),
+            Transaction::write(0x28, vec![0x0, 5]),
+        ];
+        let mut blocking_i2c = Mock::new(&expectations);
+        let mut async_i2c = Mock::new(&expectations);
+
+        run_blocking_ramp(blocking_i2c.clone()).unwrap();
+        block_on(run_async_ramp(async_i2c.clone())).unwrap();
+
+        assert_eq!(
+            blocking_i2c.clone().collect::<vec::Vec<_>>(),
+            async_i2c.clone().collect::<vec::Vec<_>>(),
+        );
+
+        blocking_i2c.done();
+        async_i2c.done();
+    }
+
+    fn run_blocking_ramp_down(i2c: Mock) -> Result<(), Ds3502Error> {
+        let mut digipot = Ds3502::blocking_init(i2c, Default::default())?;
+        digipot.write_wiper(Wiper::try_from(5)?)?;
+        digipot.ramp_to(Wiper::try_from(2)?, &mut NoopDelay, 10)?;
+        Ok(())
+    }
+
+    async fn run_async_ramp_down(i2c: Mock) -> Result<(), Ds3502Error> {
+        let mut digipot = Ds3502::async_init(i2c, Default::default()).await?;
+        digipot.async_write_wiper(Wiper::try_from(5)?).await?;
+        digipot
+            .async_ramp_to(Wiper::try_from(2)?, &mut NoopDelay, 10)
+            .await?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_ramp_to_decreasing_both() {
+        let expectations = [
+            // {blocking,async}_init
+            Transaction::write(0x28, vec![0x2, ControlRegisterMode::WiperOnly as u8]),
+            Transaction::write_read(0x28, vec![0x00], vec![0]),
+            // {async_}write_wiper(5)
+            Transaction::write(0x28, vec![0x0, 5]),
+            // {async_}ramp_to(2) steps through 4, 3, 2
+            Transaction::write(0x28, vec![0x0, 4]),
+            Transaction::write(0x28, vec![0x0, 3]),
+            Transaction::write(0x28, vec![0x0, 2]),
+        ];
+        let mut blocking_i2c = Mock::new(&expectations);
+        let mut async_i2c = Mock::new(&expectations);
+
+        run_blocking_ramp_down(blocking_i2c.clone()).unwrap();
+        block_on(run_async_ramp_down(async_i2c.clone())).unwrap();
+
+        assert_eq!(
+            blocking_i2c.clone().collect::<vec::Vec<_>>(),
+            async_i2c.clone().collect::<vec::Vec<_>>(),
+        );
+
+        blocking_i2c.done();
+        async_i2c.done();
+    }
+
+    #[test]
+    fn test_save_budget_exhausted() {
+        let config = Config {
+            save_budget: Some(1),
+            ..Default::default()
+        };
+        let expectations = [
+            Transaction::write(0x28, vec![0x2, ControlRegisterMode::WiperOnly as u8]),
+            Transaction::write_read(0x28, vec![0x00], vec![0]),
+            Transaction::write(
+                0x28,
+                vec![0x2, ControlRegisterMode::WiperAndInitialValue as u8],
+            ),
+            Transaction::write(0x28, vec![0x0, 23]),
+            Transaction::write(0x28, vec![0x2, ControlRegisterMode::WiperOnly as u8]),
+        ];
+        let mut i2c = Mock::new(&expectations);
+
+        let mut digipot = Ds3502::blocking_init(i2c.clone(), config).unwrap();
+        assert_eq!(digipot.saves_remaining(), Some(1));
+
+        let wv = Wiper::try_from(23).unwrap();
+        digipot.write_and_save_wiper(wv, &mut NoopDelay).unwrap();
+        assert_eq!(digipot.saves_remaining(), Some(0));
+
+        let err = digipot
+            .write_and_save_wiper(wv, &mut NoopDelay)
+            .unwrap_err();
+        assert_eq!(err, Ds3502Error::SaveBudgetExhausted);
+
+        i2c.done();
+    }
 }